@@ -8,16 +8,22 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
-use bp_rpc::{Client, FailureCode};
+use std::time::Duration;
+
+use bp_rpc::{Client, FailureCode, Reply, Request};
 use microservices::rpc::ServerError;
 use microservices::shell::Exec;
 
 use crate::{Command, Opts};
 
+/// Time to wait for the daemon to answer a ping before reporting it dead
+const PING_TIMEOUT: Duration = Duration::from_secs(5);
+
 impl Command {
     pub fn action_string(&self) -> String {
         match self {
             Command::None => s!(""),
+            Command::Ping => s!("Pinging bpd"),
         }
     }
 }
@@ -26,10 +32,20 @@ impl Exec for Opts {
     type Client = Client;
     type Error = ServerError<FailureCode>;
 
-    fn exec(self, _runtime: &mut Self::Client) -> Result<(), Self::Error> {
+    fn exec(self, runtime: &mut Self::Client) -> Result<(), Self::Error> {
         println!("{}...", self.command.action_string());
         match self.command {
             Command::None => {}
+            Command::Ping => {
+                runtime.set_timeout(PING_TIMEOUT)?;
+                match runtime.request(Request::Ping)? {
+                    Reply::Pong(info) => {
+                        println!("bpd is alive, uptime {} seconds", info.uptime_secs)
+                    }
+                    Reply::Failure(failure) => return Err(failure.into()),
+                    _ => return Err(ServerError::UnexpectedServerResponse),
+                }
+            }
         }
         Ok(())
     }
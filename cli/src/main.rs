@@ -22,6 +22,8 @@ extern crate clap;
 mod command;
 mod opts;
 
+use std::process;
+
 use bp_rpc::client::Client;
 use clap::Parser;
 use internet2::addr::ServiceAddr;
@@ -46,6 +48,8 @@ fn main() {
     let mut client = Client::with(&connect).expect("Error initializing client");
 
     trace!("Executing command: {}", opts.command);
-    opts.exec(&mut client)
-        .unwrap_or_else(|err| eprintln!("{} {}\n", "Error:".err(), err.err_details()));
+    if let Err(err) = opts.exec(&mut client) {
+        eprintln!("{} {}\n", "Error:".err(), err.err_details());
+        process::exit(1);
+    }
 }
@@ -46,4 +46,11 @@ pub struct Opts {
 pub enum Command {
     #[display("none")]
     None,
+
+    /// Check that the daemon is alive and report its uptime
+    ///
+    /// Exits with a non-zero status if the daemon does not reply within 5
+    /// seconds or replies with a failure.
+    #[display("ping")]
+    Ping,
 }
@@ -8,10 +8,13 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
+use std::time::Duration;
+
 use internet2::addr::ServiceAddr;
 use internet2::session::LocalSession;
 use internet2::{
-    CreateUnmarshaller, SendRecvMessage, TypedEnum, Unmarshall, Unmarshaller, ZmqSocketType,
+    presentation, transport, CreateUnmarshaller, SendRecvMessage, TypedEnum, Unmarshall,
+    Unmarshaller, ZmqSocketType,
 };
 use microservices::rpc::ServerError;
 use microservices::ZMQ_CONTEXT;
@@ -50,13 +53,29 @@ impl Client {
         })
     }
 
+    /// Limits the time [`Client::request`] waits for the daemon reply. If it
+    /// is exceeded the request fails with [`transport::Error::ServiceOffline`]
+    /// and the client must not be used for further requests.
+    pub fn set_timeout(&mut self, timeout: Duration) -> Result<(), ServerError<FailureCode>> {
+        let millis = i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX);
+        self.session_rpc.as_socket().set_rcvtimeo(millis)?;
+        Ok(())
+    }
+
     pub fn request(&mut self, request: Request) -> Result<Reply, ServerError<FailureCode>> {
         trace!("Sending request to the server: {:?}", request);
         let data = request.serialize();
         trace!("Raw request data ({} bytes): {:02X?}", data.len(), data);
         self.session_rpc.send_raw_message(&data)?;
         trace!("Awaiting reply");
-        let raw = self.session_rpc.recv_raw_message()?;
+        let raw = self.session_rpc.recv_raw_message().map_err(|err| match err {
+            presentation::Error::Transport(transport::Error::Zmq(err))
+                if zmq::Error::from(err) == zmq::Error::EAGAIN =>
+            {
+                ServerError::<FailureCode>::Transport(transport::Error::ServiceOffline)
+            }
+            err => err.into(),
+        })?;
         trace!("Got reply ({} bytes), parsing: {:02X?}", raw.len(), raw);
         let reply = self.unmarshaller.unmarshall(raw.as_slice())?;
         trace!("Reply: {:?}", reply);
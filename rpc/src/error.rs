@@ -17,11 +17,16 @@ pub enum FailureCode {
 
     /// Encoding
     Encoding = 0x02,
+
+    /// Daemon-wide request rate limit exceeded
+    RateLimited = 0x03,
 }
 
 impl From<u16> for FailureCode {
     fn from(value: u16) -> Self {
         match value {
+            0x02 => FailureCode::Encoding,
+            0x03 => FailureCode::RateLimited,
             _ => FailureCode::Unknown,
         }
     }
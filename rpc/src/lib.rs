@@ -16,6 +16,8 @@ extern crate amplify;
 extern crate internet2;
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate strict_encoding;
 
 #[cfg(feature = "serde")]
 extern crate serde_crate as serde;
@@ -30,7 +32,7 @@ mod request;
 
 pub use client::Client;
 pub use error::FailureCode;
-pub use reply::Reply;
+pub use reply::{PingInfo, Reply};
 pub use request::Request;
 
 pub const BP_NODE_RPC_ENDPOINT: &str = "0.0.0.0:61961";
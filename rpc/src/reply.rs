@@ -10,6 +10,8 @@
 
 use internet2::presentation;
 use microservices::rpc;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::{FailureCode};
 
@@ -24,6 +26,11 @@ pub enum Reply {
     #[display("success({0})")]
     Success,
 
+    /// Reply to [`crate::Request::Ping`]
+    #[api(type = 0x0002)]
+    #[display("pong({0})")]
+    Pong(PingInfo),
+
     #[api(type = 0x0000)]
     #[display("failure({0:#})")]
     #[from]
@@ -32,6 +39,16 @@ pub enum Reply {
 
 impl rpc::Reply for Reply {}
 
+/// Daemon liveness information returned in [`Reply::Pong`]
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(crate = "serde_crate"))]
+#[display("uptime {uptime_secs}s")]
+pub struct PingInfo {
+    /// Number of seconds passed since the daemon start
+    pub uptime_secs: u64,
+}
+
 impl From<presentation::Error> for Reply {
     fn from(err: presentation::Error) -> Self {
         Reply::Failure(rpc::Failure {
@@ -15,4 +15,9 @@ pub enum Request {
     #[api(type = 0x10)]
     #[display("noop")]
     Noop,
+
+    /// Liveness check, answered with [`crate::Reply::Pong`]
+    #[api(type = 0x11)]
+    #[display("ping")]
+    Ping,
 }
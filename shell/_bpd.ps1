@@ -33,6 +33,7 @@ Register-ArgumentCompleter -Native -CommandName 'bpd' -ScriptBlock {
             [CompletionResult]::new('--electrum-port', 'electrum-port', [CompletionResultType]::ParameterName, 'Customize Electrum server port number. By default the wallet will use port matching the selected network')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket name/address for RGB node RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket name/address for RGB node RPC interface')
+            [CompletionResult]::new('--max-queries-per-sec', 'max-queries-per-sec', [CompletionResultType]::ParameterName, 'Maximum number of RPC queries served by the daemon per second')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('-V', 'V', [CompletionResultType]::ParameterName, 'Print version information')
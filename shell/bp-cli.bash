@@ -18,6 +18,9 @@ _bp-cli() {
             none)
                 cmd+="__none"
                 ;;
+            ping)
+                cmd+="__ping"
+                ;;
             *)
                 ;;
         esac
@@ -25,7 +28,7 @@ _bp-cli() {
 
     case "${cmd}" in
         bp__cli)
-            opts="-h -V -R -v --help --version --rpc --verbose none help"
+            opts="-h -V -R -v --help --version --rpc --verbose none ping help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -90,6 +93,28 @@ _bp-cli() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        bp__cli__ping)
+            opts="-h -R -v --help --rpc --verbose"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --rpc)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -R)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
     esac
 }
 
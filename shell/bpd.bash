@@ -19,7 +19,7 @@ _bpd() {
 
     case "${cmd}" in
         bpd)
-            opts="-h -V -v -d -S -X -n -R -t --help --version --verbose --data-dir --store --ctl --chain --electrum-server --electrum-port --rpc --threaded"
+            opts="-h -V -v -d -S -X -n -R -t --help --version --verbose --data-dir --store --ctl --chain --electrum-server --electrum-port --rpc --max-queries-per-sec --threaded"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -73,6 +73,10 @@ _bpd() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --max-queries-per-sec)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -15,12 +15,14 @@
 #[macro_use]
 extern crate log;
 
+use std::process;
+
 use bp_node::bpd::Opts;
-use bp_node::{bpd, Config, LaunchError};
+use bp_node::{bpd, Config, EXIT_BOOTSTRAP_FAILURE};
 use clap::Parser;
 use microservices::error::BootstrapError;
 
-fn main() -> Result<(), BootstrapError<LaunchError>> {
+fn main() {
     println!("bpd: managing bp node daemon");
 
     let mut opts = Opts::parse();
@@ -44,7 +46,14 @@ fn main() -> Result<(), BootstrapError<LaunchError>> {
      */
 
     debug!("Starting runtime ...");
-    bpd::run(config).expect("running bpd runtime");
+    if let Err(err) = bpd::run(config) {
+        eprintln!("Error: {}", err);
+        let code = match err {
+            BootstrapError::AppLevel(err) => err.exit_code(),
+            _ => EXIT_BOOTSTRAP_FAILURE,
+        };
+        process::exit(code);
+    }
 
     unreachable!()
 }
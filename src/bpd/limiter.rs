@@ -0,0 +1,98 @@
+// BP Node: bitcoin blockchain indexing and notification service
+//
+// Written in 2020-2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2020-2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::num::NonZeroU32;
+use std::time::Instant;
+
+/// Token bucket limiting the number of RPC queries served per second. bpd
+/// keeps a single bucket shared by all clients, since its REP socket does not
+/// tell them apart.
+///
+/// The bucket holds up to `rate` tokens and is refilled continuously at
+/// `rate` tokens per second, so short bursts up to the per-second limit are
+/// allowed.
+#[derive(Clone, Debug)]
+pub(crate) struct RateLimiter {
+    rate: u32,
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl RateLimiter {
+    pub fn with(rate: NonZeroU32) -> Self {
+        let rate = rate.get();
+        RateLimiter {
+            rate,
+            tokens: rate as f64,
+            refilled: Instant::now(),
+        }
+    }
+
+    #[inline]
+    pub fn rate(&self) -> u32 { self.rate }
+
+    /// Takes a token from the bucket, returning `false` if there is none left
+    /// and the query must be rejected.
+    #[inline]
+    pub fn check(&mut self) -> bool { self.check_at(Instant::now()) }
+
+    /// Takes a token from the bucket refilled up to the moment `now`,
+    /// returning `false` if there is none left and the query must be rejected.
+    pub fn check_at(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.refilled).as_secs_f64();
+        self.refilled = self.refilled.max(now);
+        self.tokens = (self.tokens + elapsed * self.rate as f64).min(self.rate as f64);
+        if self.tokens < 1.0 {
+            return false;
+        }
+        self.tokens -= 1.0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn burst_above_limit() {
+        let mut limiter = RateLimiter::with(NonZeroU32::new(10).unwrap());
+        let start = limiter.refilled;
+        for _ in 0..10 {
+            assert!(limiter.check_at(start));
+        }
+        assert!(!limiter.check_at(start));
+        assert!(!limiter.check_at(start));
+    }
+
+    #[test]
+    fn recovery() {
+        let mut limiter = RateLimiter::with(NonZeroU32::new(10).unwrap());
+        let start = limiter.refilled;
+        for _ in 0..10 {
+            assert!(limiter.check_at(start));
+        }
+        assert!(!limiter.check_at(start));
+
+        // Half a token is refilled after 50 ms, one and a half after 150 ms
+        assert!(!limiter.check_at(start + Duration::from_millis(50)));
+        assert!(limiter.check_at(start + Duration::from_millis(150)));
+        assert!(!limiter.check_at(start + Duration::from_millis(150)));
+
+        // Bucket never holds more than `rate` tokens
+        let later = start + Duration::from_secs(10);
+        for _ in 0..10 {
+            assert!(limiter.check_at(later));
+        }
+        assert!(!limiter.check_at(later));
+    }
+}
@@ -9,6 +9,7 @@
 // If not, see <https://opensource.org/licenses/MIT>.
 
 mod service;
+mod limiter;
 #[cfg(feature = "server")]
 mod opts;
 
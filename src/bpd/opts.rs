@@ -8,6 +8,8 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
+use std::num::NonZeroU32;
+
 use bp_rpc::BP_NODE_RPC_ENDPOINT;
 use clap::{Parser, ValueHint};
 use internet2::addr::ServiceAddr;
@@ -18,7 +20,18 @@ use crate::opts::Opts as SharedOpts;
 /// Command-line arguments
 #[derive(Parser)]
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
-#[clap(author, version, name = "bpd", about = "RGB node managing service")]
+#[clap(
+    author,
+    version,
+    name = "bpd",
+    about = "RGB node managing service",
+    after_help = "EXIT CODES:
+    1    Failure during daemon bootstrap
+    2    Invalid command-line arguments
+    3    Unable to bind a daemon socket
+    4    IPC socket is already in use by another running process
+    5    IPC socket path points to a file which is not a socket"
+)]
 pub struct Opts {
     /// These params can be read also from the configuration file, not just
     /// command-line args or environment variables
@@ -28,6 +41,10 @@ pub struct Opts {
     /// ZMQ socket name/address for RGB node RPC interface.
     ///
     /// Internal interface for control PRC protocol communications.
+    ///
+    /// Socket can be either TCP address in form of `<ipv4 | ipv6>:<port>` – or a path
+    /// to an IPC file. Stale IPC socket file left by a previous run is removed on
+    /// start.
     #[clap(
         short = 'R',
         long = "rpc",
@@ -37,6 +54,15 @@ pub struct Opts {
     )]
     pub rpc_endpoint: ServiceAddr,
 
+    /// Maximum number of RPC queries served by the daemon per second.
+    ///
+    /// The limit is shared by all clients: once it is exceeded, queries from
+    /// any client are answered with a rate-limit failure until it recovers.
+    /// Pings are never limited. Must be greater than zero; if not given, the
+    /// number of queries is not limited.
+    #[clap(long, env = "BP_NODE_MAX_QUERIES_PER_SEC")]
+    pub max_queries_per_sec: Option<NonZeroU32>,
+
     /// Spawn daemons as threads and not processes
     #[clap(short = 't', long = "threaded")]
     pub threaded_daemons: bool,
@@ -8,7 +8,21 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
-use bp_rpc::{Reply, Request};
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::io;
+#[cfg(unix)]
+use std::os::unix::fs::FileTypeExt;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+#[cfg(unix)]
+use std::path::Path;
+use std::time::Instant;
+
+use bp_rpc::{PingInfo, Reply, Request};
+#[cfg(unix)]
+use internet2::addr::ServiceAddr;
 use internet2::session::LocalSession;
 use internet2::{
     CreateUnmarshaller, SendRecvMessage, TypedEnum, Unmarshall, Unmarshaller, ZmqSocketType,
@@ -18,6 +32,7 @@ use microservices::node::TryService;
 use microservices::rpc::ClientError;
 use microservices::ZMQ_CONTEXT;
 
+use super::limiter::RateLimiter;
 use crate::{Config, DaemonError, LaunchError};
 
 pub fn run(config: Config) -> Result<(), BootstrapError<LaunchError>> {
@@ -34,6 +49,12 @@ pub struct Runtime {
 
     /// Unmarshaller instance used for parsing RPC request
     pub(crate) unmarshaller: Unmarshaller<Request>,
+
+    /// Limiter for the rate of RPC queries, if configured
+    pub(crate) rate_limiter: Option<RateLimiter>,
+
+    /// Time the runtime was started at
+    pub(crate) started: Instant,
 }
 
 impl Runtime {
@@ -41,6 +62,9 @@ impl Runtime {
         // debug!("Initializing storage provider {:?}", config.storage_conf());
         // let storage = storage::FileDriver::with(config.storage_conf())?;
 
+        #[cfg(unix)]
+        clean_stale_socket("RPC", &config.rpc_endpoint)?;
+
         debug!("Opening RPC API socket {}", config.rpc_endpoint);
         let session_rpc = LocalSession::connect(
             ZmqSocketType::Rep,
@@ -48,17 +72,63 @@ impl Runtime {
             None,
             None,
             &ZMQ_CONTEXT,
-        )?;
+        )
+        .map_err(|err| LaunchError::Bind {
+            service: "RPC",
+            addr: config.rpc_endpoint.clone(),
+            err,
+        })?;
 
         info!("bpd runtime started successfully");
 
         Ok(Self {
             session_rpc,
             unmarshaller: Request::create_unmarshaller(),
+            rate_limiter: config.max_queries_per_sec.map(RateLimiter::with),
+            started: Instant::now(),
         })
     }
 }
 
+/// Removes IPC socket file left by a previous bpd run. Fails if the socket is
+/// still served by a running process, since binding over it would silently
+/// take it over, and if the path points to something which is not a socket.
+#[cfg(unix)]
+fn clean_stale_socket(service: &'static str, addr: &ServiceAddr) -> Result<(), LaunchError> {
+    let path = match addr {
+        ServiceAddr::Ipc(path) => Path::new(path),
+        _ => return Ok(()),
+    };
+    let bind_err = |err: io::Error| LaunchError::Bind {
+        service,
+        addr: addr.clone(),
+        err: err.into(),
+    };
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(bind_err(err)),
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(LaunchError::NotSocket {
+            service,
+            addr: addr.clone(),
+        });
+    }
+    match UnixStream::connect(path) {
+        Ok(_) => {
+            return Err(LaunchError::SocketInUse {
+                service,
+                addr: addr.clone(),
+            })
+        }
+        Err(err) if err.kind() == io::ErrorKind::ConnectionRefused => {}
+        Err(err) => return Err(bind_err(err)),
+    }
+    warn!("Removing stale {} socket file {}", service, path.display());
+    fs::remove_file(path).map_err(bind_err)
+}
+
 impl TryService for Runtime {
     type ErrorType = ClientError;
 
@@ -93,9 +163,146 @@ impl Runtime {
         trace!("Got {} bytes over ZMQ RPC", raw.len());
         let request = (&*self.unmarshaller.unmarshall(raw.as_slice())?).clone();
         debug!("Received ZMQ RPC request #{}: {}", request.get_type(), request);
+        // Health checks must not be rejected by the rate limiter
+        if request != Request::Ping {
+            if let Some(limiter) = &mut self.rate_limiter {
+                if !limiter.check() {
+                    warn!("RPC query rate limit of {}/s is exceeded", limiter.rate());
+                    return Err(DaemonError::RateLimited(limiter.rate()).into());
+                }
+            }
+        }
         match request {
             Request::Noop => Ok(Reply::Success) as Result<_, DaemonError>,
+            Request::Ping => Ok(Reply::Pong(PingInfo {
+                uptime_secs: self.started.elapsed().as_secs(),
+            })),
         }
         .map_err(Reply::from)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::env;
+    use std::net::TcpListener;
+    use std::num::NonZeroU32;
+    #[cfg(unix)]
+    use std::os::unix::net::UnixListener;
+    #[cfg(unix)]
+    use std::path::PathBuf;
+    #[cfg(unix)]
+    use std::process;
+
+    use bp_rpc::FailureCode;
+    use internet2::addr::ServiceAddr;
+
+    use super::*;
+    use crate::EXIT_BIND_FAILURE;
+
+    fn config(rpc_endpoint: ServiceAddr) -> Config {
+        Config {
+            rpc_endpoint,
+            ctl_endpoint: ServiceAddr::Inproc(s!("ctl")),
+            store_endpoint: ServiceAddr::Inproc(s!("store")),
+            data_dir: env::temp_dir(),
+            electrum_url: s!("127.0.0.1:60001"),
+            threaded: true,
+            max_queries_per_sec: None,
+        }
+    }
+
+    #[cfg(unix)]
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("bpd-test-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[cfg(unix)]
+    fn ipc_addr(path: &Path) -> ServiceAddr { ServiceAddr::Ipc(path.display().to_string()) }
+
+    #[test]
+    #[cfg(unix)]
+    fn stale_socket_missing() {
+        let path = temp_dir("missing").join("rpc");
+        assert_eq!(clean_stale_socket("RPC", &ipc_addr(&path)), Ok(()));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn stale_socket_not_socket() {
+        let path = temp_dir("not-socket").join("rpc");
+        fs::write(&path, b"data").unwrap();
+        assert!(matches!(
+            clean_stale_socket("RPC", &ipc_addr(&path)),
+            Err(LaunchError::NotSocket { service: "RPC", .. })
+        ));
+        assert_eq!(fs::read(&path).unwrap(), b"data");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn stale_socket_in_use() {
+        let path = temp_dir("in-use").join("rpc");
+        let _listener = UnixListener::bind(&path).unwrap();
+        assert!(matches!(
+            clean_stale_socket("RPC", &ipc_addr(&path)),
+            Err(LaunchError::SocketInUse { service: "RPC", .. })
+        ));
+        assert!(path.exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn stale_socket_removed() {
+        let path = temp_dir("stale").join("rpc");
+        drop(UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+        assert_eq!(clean_stale_socket("RPC", &ipc_addr(&path)), Ok(()));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn stale_socket_tcp() {
+        let addr = ServiceAddr::Tcp("127.0.0.1:61961".parse().unwrap());
+        assert_eq!(clean_stale_socket("RPC", &addr), Ok(()));
+    }
+
+    #[test]
+    fn bind_failure() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = ServiceAddr::Tcp(listener.local_addr().unwrap());
+        match Runtime::init(config(addr)) {
+            Err(BootstrapError::AppLevel(err)) => {
+                assert!(matches!(err, LaunchError::Bind { service: "RPC", .. }));
+                assert_eq!(err.exit_code(), EXIT_BIND_FAILURE);
+            }
+            Err(err) => panic!("unexpected error {}", err),
+            Ok(_) => panic!("RPC socket bound to a port which is already in use"),
+        }
+    }
+
+    #[test]
+    fn rate_limited() {
+        let mut config = config(ServiceAddr::Inproc(s!("rate-limited")));
+        config.max_queries_per_sec = NonZeroU32::new(2);
+        let mut runtime = Runtime::init(config).unwrap_or_else(|err| panic!("{}", err));
+
+        let noop = Request::Noop.serialize();
+        assert_eq!(runtime.rpc_process(noop.clone()), Ok(Reply::Success));
+        assert_eq!(runtime.rpc_process(noop.clone()), Ok(Reply::Success));
+        match runtime.rpc_process(noop) {
+            Err(Reply::Failure(failure)) => {
+                assert_eq!(failure.code, FailureCode::RateLimited.into())
+            }
+            reply => panic!("query above the rate limit is served: {:?}", reply),
+        }
+
+        // Health checks are exempt from the limit
+        assert!(matches!(runtime.rpc_process(Request::Ping.serialize()), Ok(Reply::Pong(_))));
+    }
+}
@@ -8,6 +8,7 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
+use std::num::NonZeroU32;
 use std::path::PathBuf;
 
 use bp_rpc::BP_NODE_RPC_ENDPOINT;
@@ -42,6 +43,10 @@ pub struct Config {
 
     /// Indicates whether deamons should be spawned as threads (true) or as child processes (false)
     pub threaded: bool,
+
+    /// Maximum number of RPC queries served per second, shared by all clients;
+    /// unlimited if `None`
+    pub max_queries_per_sec: Option<NonZeroU32>,
 }
 
 // TODO: Move to descriptor wallet
@@ -72,6 +77,7 @@ impl From<Opts> for Config {
             store_endpoint: opts.store_endpoint,
             electrum_url,
             threaded: true,
+            max_queries_per_sec: None,
         }
     }
 }
@@ -81,6 +87,7 @@ impl From<bpd::Opts> for Config {
         let mut config = Config::from(opts.shared);
         config.set_rpc_endpoint(opts.rpc_endpoint);
         config.threaded = opts.threaded_daemons;
+        config.max_queries_per_sec = opts.max_queries_per_sec;
         config
     }
 }
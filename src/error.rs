@@ -8,22 +8,67 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
+use internet2::addr::ServiceAddr;
+use internet2::transport;
 use microservices::rpc;
 use bp_rpc::{FailureCode, Reply};
 
+/// Daemon exit code for bootstrap failures other than [`LaunchError`]s
+pub const EXIT_BOOTSTRAP_FAILURE: i32 = 1;
+// Exit code 2 is used by clap for invalid command-line arguments
+/// Daemon exit code for [`LaunchError::Bind`]
+pub const EXIT_BIND_FAILURE: i32 = 3;
+/// Daemon exit code for [`LaunchError::SocketInUse`]
+pub const EXIT_SOCKET_IN_USE: i32 = 4;
+/// Daemon exit code for [`LaunchError::NotSocket`]
+pub const EXIT_NOT_SOCKET: i32 = 5;
+
 #[derive(Clone, PartialEq, Eq, Debug, Display, Error, From)]
 #[display(doc_comments)]
 pub enum LaunchError {
+    /// unable to bind {service} socket to {addr}: {err}
+    Bind {
+        service: &'static str,
+        addr: ServiceAddr,
+        err: transport::Error,
+    },
+
+    /// {service} socket {addr} is already in use by another running process
+    SocketInUse {
+        service: &'static str,
+        addr: ServiceAddr,
+    },
+
+    /// {service} socket path {addr} points to an existing file which is not a
+    /// socket
+    NotSocket {
+        service: &'static str,
+        addr: ServiceAddr,
+    },
 }
 
 impl microservices::error::Error for LaunchError {}
 
+impl LaunchError {
+    /// Process exit code used by the daemon when failing with this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            LaunchError::Bind { .. } => EXIT_BIND_FAILURE,
+            LaunchError::SocketInUse { .. } => EXIT_SOCKET_IN_USE,
+            LaunchError::NotSocket { .. } => EXIT_NOT_SOCKET,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Display, Error, From)]
 #[display(doc_comments)]
 pub enum DaemonError {
     #[from]
     #[display(inner)]
     Encoding(strict_encoding::Error),
+
+    /// daemon-wide request rate limit of {0} queries per second is exceeded
+    RateLimited(u32),
 }
 
 impl microservices::error::Error for DaemonError {}
@@ -32,6 +77,7 @@ impl From<DaemonError> for Reply {
     fn from(err: DaemonError) -> Self {
         let code = match err {
             DaemonError::Encoding(_) => FailureCode::Encoding,
+            DaemonError::RateLimited(_) => FailureCode::RateLimited,
         };
         Reply::Failure(rpc::Failure {
             code: code.into(),
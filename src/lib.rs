@@ -20,6 +20,9 @@ pub mod bpd;
 mod opts;
 
 pub use config::Config;
-pub use error::{DaemonError, LaunchError};
+pub use error::{
+    DaemonError, LaunchError, EXIT_BIND_FAILURE, EXIT_BOOTSTRAP_FAILURE, EXIT_NOT_SOCKET,
+    EXIT_SOCKET_IN_USE,
+};
 #[cfg(feature = "server")]
 pub use opts::Opts;